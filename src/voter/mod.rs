@@ -0,0 +1,145 @@
+// Copyright 2018-2019 Parity Technologies (UK) Ltd
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A voter in GRANDPA. This votes in one or more rounds, driving rounds to
+//! completion and responding to any incoming votes.
+//!
+//! This crate does not itself implement any form of authority selection,
+//! network communication, or block production logic. Instead, it relies on
+//! an external `Environment` to supply all of that, and only implements the
+//! consensus logic itself.
+
+use std::fmt::Debug;
+
+use futures::{Sink, Stream};
+
+use crate::{BlockNumberOps, Commit, Message, Precommit, Prevote, PrimaryPropose, SignedMessage};
+
+pub(crate) mod voting_round;
+
+// Re-exported (not just crate-visible) because downstream consumers need to
+// persist and forward `EquivocationProof`s (e.g. for slashing) and `CatchUp`
+// proofs, and because a `pub` trait method can't take a type that callers
+// outside the crate can't otherwise name.
+pub use voting_round::{verify_equivocation_proof, CatchUp, EquivocationProof, NewRoundReason};
+
+/// The timers to drive a round with, handed out by `Environment::round_data`.
+#[derive(Debug, Clone)]
+pub struct RoundTimers<Timer> {
+	/// Resolves once we should cast our prevote, if we haven't already.
+	pub prevote_timer: Timer,
+	/// Resolves once we should cast our precommit, if we haven't already.
+	pub precommit_timer: Timer,
+}
+
+/// Communication between nodes that is not round-localized.
+pub trait Environment<H: Ord, N: BlockNumberOps>: Sized {
+	/// Associated timer type for the environment. Typically a `Future` that
+	/// resolves when a given duration has elapsed.
+	type Timer: futures::Future<Output = Result<(), Self::Error>> + Unpin;
+	/// The associated Id for the Environment.
+	type Id: Clone + Eq + Debug + Ord;
+	/// The associated Signature type for the Environment.
+	type Signature: Clone + Eq;
+	/// The input stream used to receive messages from the outside world.
+	type In: Stream<Item = Result<SignedMessage<H, N, Self::Signature, Self::Id>, Self::Error>>
+		+ Unpin;
+	/// The output sink used to send messages to the outside world.
+	type Out: Sink<Message<H, N>, Error = Self::Error> + Unpin;
+	/// A stream of round numbers that peers have asked us to send a catch-up
+	/// proof for, so they can jump straight to that round instead of
+	/// replaying every round since their own last seen one.
+	type CatchUpIn: Stream<Item = u64> + Unpin;
+	/// A stream of catch-up proofs received from peers in response to a
+	/// catch-up request we sent out.
+	type CatchUpOut: Stream<Item = CatchUp<H, N, Self::Signature, Self::Id>> + Unpin;
+	/// The associated Error type.
+	type Error: From<crate::Error> + std::error::Error;
+
+	/// Whether `block` is equal to or a descendent of `base`.
+	fn is_equal_or_descendent_of(&self, base: H, block: H) -> bool;
+
+	/// Returns the best block containing `base` that we could vote for.
+	fn best_chain_containing(&self, base: H) -> Option<(H, N)>;
+
+	/// Produce a signature for a message cast under our own identity.
+	fn sign(&self, message: Message<H, N>) -> Self::Signature;
+
+	/// Note that we've sent a primary block hint for the given round.
+	fn proposed(&self, round: u64, propose: PrimaryPropose<H, N>) -> Result<(), Self::Error>;
+
+	/// Note that we've prevoted for the given block in the given round.
+	fn prevoted(&self, round: u64, prevote: Prevote<H, N>) -> Result<(), Self::Error>;
+
+	/// Note that we've precommitted for the given block in the given round.
+	fn precommitted(&self, round: u64, precommit: Precommit<H, N>) -> Result<(), Self::Error>;
+
+	/// Note an equivocation in prevotes.
+	fn prevote_equivocation(
+		&self,
+		round: u64,
+		equivocation: EquivocationProof<H, N, Self::Signature, Self::Id>,
+	);
+
+	/// Note an equivocation in precommits.
+	fn precommit_equivocation(
+		&self,
+		round: u64,
+		equivocation: EquivocationProof<H, N, Self::Signature, Self::Id>,
+	);
+
+	/// Finalize a block, given a commit that justifies it.
+	fn finalize_block(
+		&self,
+		round: u64,
+		commit: Commit<H, N, Self::Signature, Self::Id>,
+	) -> Result<(), Self::Error>;
+
+	/// A stream of round numbers that peers have requested a catch-up proof
+	/// for. Polled alongside everything else a round listens for.
+	fn catch_up_requests(&self) -> Self::CatchUpIn;
+
+	/// Send a catch-up proof for `round` in response to a request received
+	/// from `catch_up_requests`.
+	fn send_catch_up_response(
+		&self,
+		round: u64,
+		catch_up: CatchUp<H, N, Self::Signature, Self::Id>,
+	) -> Result<(), Self::Error>;
+
+	/// A stream of catch-up proofs received from peers, in response to a
+	/// catch-up request we sent out while lagging behind.
+	fn catch_up_responses(&self) -> Self::CatchUpOut;
+
+	/// A timer used to periodically rebroadcast our own last-cast round
+	/// messages, so peers that missed them the first time still receive
+	/// them. Implementations that want to tune the interval can return a
+	/// shorter or longer timer on each call; returning a timer that never
+	/// resolves disables rebroadcasting entirely.
+	fn rebroadcast_timer(&self) -> Self::Timer;
+
+	/// The timers to use for `round`, given why the previous round ended and
+	/// how many of its predecessors in a row also ended in `Timeout`.
+	/// Implementations should back the timers off (e.g. exponentially) as
+	/// `consecutive_timeouts` grows, and reset to their baseline duration once
+	/// `consecutive_timeouts` is back to zero. A round reached through
+	/// catch-up reports `QuorumAchieved` but keeps the running count, so
+	/// `reason` alone does not mark the end of a back-off streak.
+	fn round_data(
+		&self,
+		round: u64,
+		reason: NewRoundReason,
+		consecutive_timeouts: usize,
+	) -> RoundTimers<Self::Timer>;
+}
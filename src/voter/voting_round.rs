@@ -27,8 +27,8 @@ use crate::{
 	round::{Round, State as RoundState},
 	validate_commit,
 	weights::VoteWeight,
-	BlockNumberOps, Commit, HistoricalVotes, ImportResult, Message, Precommit, Prevote,
-	PrimaryPropose, SignedMessage, SignedPrecommit,
+	BlockNumberOps, Commit, Equivocation, HistoricalVotes, ImportResult, Message, Precommit,
+	Prevote, PrimaryPropose, SignedMessage, SignedPrecommit,
 };
 
 /// The state of a voting round.
@@ -50,22 +50,37 @@ impl<T> std::fmt::Debug for State<T> {
 	}
 }
 
+/// Why a new round was started: either the previous round finalized its
+/// estimate normally, or it only did so after one of its timers ran out,
+/// which is a sign the network is degraded. The voter passes this (along with
+/// a running count of consecutive `Timeout`s) to `Environment::round_data`
+/// when requesting the next round's timers, so implementations can back off
+/// exponentially and reset to their baseline duration once a round finalizes
+/// within its timers again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewRoundReason {
+	/// The previous round finalized its estimate before its timers ran out.
+	QuorumAchieved,
+	/// The previous round only finalized its estimate after a timer fired.
+	Timeout,
+}
+
 /// Whether we should vote in the current round (i.e. push votes to the sink.)
-enum Voting {
+pub(super) enum Voting<Id> {
 	/// Voting is disabled for the current round.
 	No,
 	/// Voting is enabled for the current round (prevotes and precommits.)
-	Yes,
+	Yes(Id),
 	/// Voting is enabled for the current round and we are the primary proposer
 	/// (we can also push primary propose messages).
-	Primary,
+	Primary(Id),
 }
 
-impl Voting {
+impl<Id> Voting<Id> {
 	/// Whether the voter should cast round votes (prevotes and precommits.)
 	fn is_active(&self) -> bool {
 		match self {
-			Voting::Yes | Voting::Primary => true,
+			Voting::Yes(_) | Voting::Primary(_) => true,
 			_ => false,
 		}
 	}
@@ -73,10 +88,18 @@ impl Voting {
 	/// Whether the voter is the primary proposer.
 	fn is_primary(&self) -> bool {
 		match self {
-			Voting::Primary => true,
+			Voting::Primary(_) => true,
 			_ => false,
 		}
 	}
+
+	/// Our own id, if we are voting in this round.
+	fn id(&self) -> Option<&Id> {
+		match self {
+			Voting::Yes(id) | Voting::Primary(id) => Some(id),
+			Voting::No => None,
+		}
+	}
 }
 
 /// Logic for a voter on a specific round.
@@ -86,7 +109,7 @@ where
 	E: Environment<H, N>,
 {
 	env: Arc<E>,
-	voting: Voting,
+	voting: Voting<E::Id>,
 	incoming: Fuse<E::In>,
 	outgoing: E::Out,
 	round: Round<E::Id, H, N, E::Signature>,
@@ -95,6 +118,22 @@ where
 	best_finalized: Option<Commit<H, N, E::Signature, E::Id>>,
 	last_round_state: Option<RoundState<H, N>>,
 	last_round_state_updates: Option<UnboundedReceiver<RoundState<H, N>>>,
+	rebroadcast_timer: E::Timer,
+	last_primary_propose: Option<PrimaryPropose<H, N>>,
+	last_prevote: Option<Prevote<H, N>>,
+	last_precommit: Option<Precommit<H, N>>,
+	consecutive_timeouts: usize,
+	timed_out: bool,
+	// whether this round was built by `from_catch_up` rather than voted
+	// through from `State::Start`. such a round starts (and, per
+	// `last_round_estimate_finalized`'s `None` arm, immediately concludes) in
+	// `State::Precommitted`, so `prevote`/`precommit` never run for it and
+	// `timed_out` can never become true - `run` needs this flag to tell that
+	// apart from a real `QuorumAchieved` and avoid resetting an in-progress
+	// back-off streak just because we fast-forwarded past a round.
+	caught_up: bool,
+	catch_up_requests: Fuse<E::CatchUpIn>,
+	catch_up_responses: Fuse<E::CatchUpOut>,
 }
 
 impl<H, N, E> VotingRound<H, N, E>
@@ -132,8 +171,10 @@ where
 					.import_prevote(&*self.env, prevote, id, signature)?;
 
 				if let Some(equivocation) = import_result.equivocation {
-					self.env
-						.prevote_equivocation(self.round.number(), equivocation);
+					self.env.prevote_equivocation(
+						self.round.number(),
+						EquivocationProof::from_prevote(equivocation),
+					);
 				}
 			}
 			Message::Precommit(precommit) => {
@@ -142,8 +183,10 @@ where
 					.import_precommit(&*self.env, precommit, id, signature)?;
 
 				if let Some(equivocation) = import_result.equivocation {
-					self.env
-						.precommit_equivocation(self.round.number(), equivocation);
+					self.env.precommit_equivocation(
+						self.round.number(),
+						EquivocationProof::from_precommit(equivocation),
+					);
 				}
 			}
 			Message::PrimaryPropose(primary) => {
@@ -160,6 +203,28 @@ where
 		Ok(())
 	}
 
+	/// Re-send the messages we have already cast this round, for liveness under
+	/// message loss. This sends unconditionally; `run` only calls it while the
+	/// round is not yet completable.
+	async fn rebroadcast(&mut self) -> Result<(), E::Error> {
+		if let Some(primary) = self.last_primary_propose.clone() {
+			trace!(target: "afg", "Rebroadcasting primary block hint for round {}", self.round.number());
+			self.outgoing.send(Message::PrimaryPropose(primary)).await?;
+		}
+
+		if let Some(prevote) = self.last_prevote.clone() {
+			trace!(target: "afg", "Rebroadcasting prevote for round {}", self.round.number());
+			self.outgoing.send(Message::Prevote(prevote)).await?;
+		}
+
+		if let Some(precommit) = self.last_precommit.clone() {
+			trace!(target: "afg", "Rebroadcasting precommit for round {}", self.round.number());
+			self.outgoing.send(Message::Precommit(precommit)).await?;
+		}
+
+		Ok(())
+	}
+
 	async fn primary_propose(
 		&mut self,
 		last_round_state: &RoundState<H, N>,
@@ -183,7 +248,8 @@ where
 					};
 
 					self.env.proposed(self.round.number(), primary.clone())?;
-					self.outgoing.send(Message::PrimaryPropose(primary)).await?;
+					self.outgoing.send(Message::PrimaryPropose(primary.clone())).await?;
+					self.last_primary_propose = Some(primary);
 
 					return Ok(true);
 				} else {
@@ -205,23 +271,180 @@ where
 		Ok(false)
 	}
 
-	fn prevote(
+	/// Construct a prevote for the current round, if we have enough information
+	/// to do so.
+	///
+	/// The target is the best block we can see, found via
+	/// `Environment::best_chain_containing` starting from our primary block hint
+	/// (if we have one and it descends from the last round's estimate), falling
+	/// back to the last round's estimate otherwise. The result is never allowed
+	/// to regress below the last round's estimate.
+	fn construct_prevote(&self, last_round_state: &RoundState<H, N>) -> Option<Prevote<H, N>> {
+		let last_round_estimate = last_round_state.estimate.clone()?;
+
+		let mut find_descendent_of = last_round_estimate.0.clone();
+
+		if let Some(ref primary_block) = self.primary_block {
+			// we will vote for the primary block hint as long as it is a descendent
+			// of the last round's estimate.
+			if self
+				.env
+				.is_equal_or_descendent_of(last_round_estimate.0.clone(), primary_block.0.clone())
+			{
+				find_descendent_of = primary_block.0.clone();
+			}
+		}
+
+		let best_chain = self.env.best_chain_containing(find_descendent_of)?;
+
+		// ensure we don't vote for anything lower than the last round's estimate.
+		let target = if self
+			.env
+			.is_equal_or_descendent_of(last_round_estimate.0.clone(), best_chain.0.clone())
+		{
+			best_chain
+		} else {
+			trace!(target: "afg",
+				"Best chain containing our primary hint is not a descendent of the last round's estimate, \
+				voting for the estimate instead for round {}", self.round.number(),
+			);
+
+			last_round_estimate
+		};
+
+		Some(Prevote {
+			target_hash: target.0,
+			target_number: target.1,
+		})
+	}
+
+	async fn prevote(
 		&mut self,
 		prevote_timer_ready: bool,
 		last_round_state: &RoundState<H, N>,
 	) -> Result<bool, E::Error> {
+		if !self.voting.is_active() {
+			return Ok(false);
+		}
+
+		// we can cast our prevote ahead of the timer if the round is already
+		// prevote-completable, e.g. because we observed enough prevotes to
+		// reach a conclusion without waiting out the full timer.
+		let completable = self.round.completable();
+		if !prevote_timer_ready && !completable {
+			return Ok(false);
+		}
+
+		// only count this as a timeout if we were still waiting on the round to
+		// become completable when the timer fired; if it was already completable
+		// we just happened to prevote at the same moment, not because the
+		// network was slow.
+		if prevote_timer_ready && !completable {
+			self.timed_out = true;
+		}
+
+		let prevote = match self.construct_prevote(last_round_state) {
+			Some(prevote) => prevote,
+			None => return Ok(false),
+		};
+
+		debug!(target: "afg", "Casting prevote for round {}", self.round.number());
+
+		if let Some(id) = self.voting.id().cloned() {
+			let signature = self.env.sign(Message::Prevote(prevote.clone()));
+			self.round
+				.import_prevote(&*self.env, prevote.clone(), id, signature)?;
+		}
+
+		self.env.prevoted(self.round.number(), prevote.clone())?;
+		self.outgoing.send(Message::Prevote(prevote.clone())).await?;
+		self.last_prevote = Some(prevote);
+
 		Ok(true)
 	}
 
-	fn precommit(
+	async fn precommit(
 		&mut self,
 		precommit_timer_ready: bool,
 		last_round_state: &RoundState<H, N>,
 	) -> Result<bool, E::Error> {
+		if !self.voting.is_active() {
+			return Ok(false);
+		}
+
+		// we can precommit ahead of the timer once the round is prevote-completable.
+		let completable = self.round.completable();
+		if !precommit_timer_ready && !completable {
+			return Ok(false);
+		}
+
+		// only count this as a timeout if we were still waiting on the round to
+		// become completable when the timer fired; if it was already completable
+		// we just happened to precommit at the same moment, not because the
+		// network was slow.
+		if precommit_timer_ready && !completable {
+			self.timed_out = true;
+		}
+
+		let target = match self.round.state().prevote_ghost {
+			Some(target) => target,
+			None => return Ok(false),
+		};
+
+		// never precommit for anything lower than the last round's estimate.
+		if let Some(ref last_round_estimate) = last_round_state.estimate {
+			if !self
+				.env
+				.is_equal_or_descendent_of(last_round_estimate.0.clone(), target.0.clone())
+			{
+				trace!(target: "afg",
+					"Refusing to precommit for round {} below the last round's estimate",
+					self.round.number(),
+				);
+
+				return Ok(false);
+			}
+		}
+
+		debug!(target: "afg", "Casting precommit for round {}", self.round.number());
+
+		let precommit = Precommit {
+			target_hash: target.0,
+			target_number: target.1,
+		};
+
+		if let Some(id) = self.voting.id().cloned() {
+			let signature = self.env.sign(Message::Precommit(precommit.clone()));
+			self.round
+				.import_precommit(&*self.env, precommit.clone(), id, signature)?;
+		}
+
+		self.env
+			.precommitted(self.round.number(), precommit.clone())?;
+		self.outgoing.send(Message::Precommit(precommit.clone())).await?;
+		self.last_precommit = Some(precommit);
+
 		Ok(true)
 	}
 
-	pub async fn run(mut self) -> Result<(), E::Error> {
+	/// Drive this round to completion: importing and casting votes, and
+	/// tracking the last round's state until our own estimate is finalized.
+	/// Resolves with a `BackgroundRound` that takes over importing this
+	/// round's late votes and emitting its commits, the reason the round
+	/// concluded, and the number of consecutive rounds (including this one)
+	/// that ended in `NewRoundReason::Timeout`.
+	///
+	/// The caller is responsible for polling the returned `BackgroundRound`
+	/// to completion (e.g. by spawning its `run` future, or driving it
+	/// alongside the next round in a `FuturesUnordered`) - this function only
+	/// constructs it, it does not drive it. A `BackgroundRound` that's never
+	/// polled silently drops late prevotes and precommits for the round it
+	/// covers, never reports the equivocations among them, and never emits
+	/// the commits that would otherwise keep justifying finality as later
+	/// rounds push the finalized block forward.
+	pub async fn run(
+		mut self,
+	) -> Result<(BackgroundRound<H, N, E>, NewRoundReason, usize), E::Error> {
 		let mut last_round_state_updates = match self.last_round_state_updates.take() {
 			Some(stream) => stream.boxed_local().fuse(),
 			None => futures::stream::pending().boxed_local().fuse(),
@@ -238,6 +461,38 @@ where
 					state = last_round_state_updates.select_next_some() => {
 						last_round_state = Some(state);
 					}
+					_ = &mut self.rebroadcast_timer => {
+						if !self.round.completable() {
+							self.rebroadcast().await?;
+						}
+
+						self.rebroadcast_timer = self.env.rebroadcast_timer();
+					}
+					target_round = self.catch_up_requests.select_next_some() => {
+						if let Some(catch_up) = self.construct_catch_up(target_round) {
+							self.env.send_catch_up_response(target_round, catch_up)?;
+						}
+					}
+					catch_up = self.catch_up_responses.select_next_some() => {
+						// a peer answered a catch-up request of ours. we can only act on
+						// it once we're able to jump straight to an arbitrary round
+						// (which needs a fresh `Round` for `catch_up.round_number` that
+						// nothing here constructs yet), so for now just make sure a
+						// proof for a round we've already moved past doesn't go missing
+						// silently.
+						if catch_up.round_number <= self.round.number() {
+							trace!(target: "afg",
+								"Ignoring catch-up proof for round {}, already at round {}",
+								catch_up.round_number, self.round.number(),
+							);
+						} else {
+							debug!(target: "afg",
+								"Received catch-up proof for round {} while at round {}, but cannot \
+								act on it without a way to construct a fresh Round for it",
+								catch_up.round_number, self.round.number(),
+							);
+						}
+					}
 					_ = &mut $timer => {}
 				}
 				$timer.is_terminated()
@@ -254,7 +509,9 @@ where
 
 					if let Some(last_round_state) = last_round_state.as_ref() {
 						let proposed = self.primary_propose(last_round_state).await?;
-						let prevoted = self.prevote(prevote_timer_ready, last_round_state)?;
+						let prevoted = self
+							.prevote(prevote_timer_ready, last_round_state)
+							.await?;
 
 						if prevoted {
 							self.state = Some(State::Prevoted(precommit_timer));
@@ -271,7 +528,9 @@ where
 					let prevote_timer_ready = handle_inputs!(prevote_timer);
 
 					if let Some(last_round_state) = last_round_state.as_ref() {
-						let prevoted = self.prevote(prevote_timer_ready, last_round_state)?;
+						let prevoted = self
+							.prevote(prevote_timer_ready, last_round_state)
+							.await?;
 
 						if prevoted {
 							self.state = Some(State::Prevoted(precommit_timer));
@@ -286,8 +545,9 @@ where
 					let precommit_timer_ready = handle_inputs!(precommit_timer);
 
 					if let Some(last_round_state) = last_round_state.as_ref() {
-						let precommitted =
-							self.precommit(precommit_timer_ready, last_round_state)?;
+						let precommitted = self
+							.precommit(precommit_timer_ready, last_round_state)
+							.await?;
 
 						if precommitted {
 							self.state = Some(State::Precommitted);
@@ -338,10 +598,571 @@ where
 				};
 
 				if last_round_estimate_finalized {
-					// TODO: return background round
-					return Ok(());
+					let reason = if self.timed_out {
+						NewRoundReason::Timeout
+					} else {
+						NewRoundReason::QuorumAchieved
+					};
+
+					let consecutive_timeouts = match reason {
+						NewRoundReason::Timeout => self.consecutive_timeouts + 1,
+						// a round we caught up to concludes immediately, without ever
+						// giving `timed_out` a chance to become true, so `reason` is
+						// always `QuorumAchieved` here regardless of whether we were
+						// mid-back-off when we caught up. Carry the streak through
+						// unchanged rather than resetting it on a "quorum" that was
+						// never actually voted on.
+						NewRoundReason::QuorumAchieved if self.caught_up => self.consecutive_timeouts,
+						NewRoundReason::QuorumAchieved => 0,
+					};
+
+					let background = BackgroundRound {
+						env: self.env,
+						incoming: self.incoming,
+						outgoing: self.outgoing,
+						round: self.round,
+						finalized: self.best_finalized,
+						catch_up_requests: self.catch_up_requests,
+						catch_up_responses: self.catch_up_responses,
+					};
+
+					return Ok((background, reason, consecutive_timeouts));
 				}
 			}
 		}
 	}
+
+	/// Assemble a catch-up proof for `target_round`, to be sent via
+	/// `Environment::send_catch_up_response` in reply to a peer that asked to
+	/// catch up to it. Returns `None` if `target_round` isn't this round, or
+	/// if we haven't imported enough votes yet to justify the round's
+	/// estimate.
+	pub(super) fn construct_catch_up(
+		&self,
+		target_round: u64,
+	) -> Option<CatchUp<H, N, E::Signature, E::Id>> {
+		if target_round != self.round.number() || !self.round.completable() {
+			return None;
+		}
+
+		let historical_votes = self.round.historical_votes();
+
+		let prevotes = historical_votes.prevotes().to_vec();
+		let precommits = historical_votes.precommits().to_vec();
+
+		let (base_hash, base_number) = self.round.base();
+
+		Some(CatchUp {
+			round_number: self.round.number(),
+			prevotes,
+			precommits,
+			base_hash,
+			base_number,
+		})
+	}
+
+	/// Build a `VotingRound` for `round` from a `catch_up` proof received from
+	/// a peer, instead of playing the round forward message by message. Fails
+	/// the same way `validate_catch_up` does if the proof doesn't justify
+	/// `round`'s estimate.
+	///
+	/// The returned round starts out already precommitted: catching up only
+	/// makes sense once the proof is completable, at which point there's
+	/// nothing left for us to vote on in it. `consecutive_timeouts` carries
+	/// forward the streak from whatever round we caught up from, so jumping
+	/// ahead doesn't reset a timer back-off that's already in progress.
+	#[allow(clippy::too_many_arguments)]
+	pub(super) fn from_catch_up(
+		env: Arc<E>,
+		voting: Voting<E::Id>,
+		incoming: E::In,
+		outgoing: E::Out,
+		round: Round<E::Id, H, N, E::Signature>,
+		catch_up: CatchUp<H, N, E::Signature, E::Id>,
+		rebroadcast_timer: E::Timer,
+		consecutive_timeouts: usize,
+	) -> Result<Self, CatchUpError<E::Error>> {
+		let round = validate_catch_up(&*env, round, catch_up)?;
+		let catch_up_requests = env.catch_up_requests().fuse();
+		let catch_up_responses = env.catch_up_responses().fuse();
+
+		Ok(VotingRound {
+			env,
+			voting,
+			incoming: incoming.fuse(),
+			outgoing,
+			round,
+			state: Some(State::Precommitted),
+			primary_block: None,
+			best_finalized: None,
+			last_round_state: None,
+			last_round_state_updates: None,
+			rebroadcast_timer,
+			last_primary_propose: None,
+			last_prevote: None,
+			last_precommit: None,
+			consecutive_timeouts,
+			timed_out: false,
+			caught_up: true,
+			catch_up_requests,
+			catch_up_responses,
+		})
+	}
+}
+
+/// A round that has already concluded (its estimate has been finalized) but
+/// is kept running in the background. Earlier rounds are kept alive like this
+/// so that late prevotes and precommits are still imported (and equivocations
+/// among them still caught and reported), and so that the round keeps
+/// producing commits for as long as later precommits push its finalized block
+/// forward.
+pub(super) struct BackgroundRound<H, N, E>
+where
+	H: Ord,
+	E: Environment<H, N>,
+{
+	env: Arc<E>,
+	incoming: Fuse<E::In>,
+	outgoing: E::Out,
+	round: Round<E::Id, H, N, E::Signature>,
+	finalized: Option<Commit<H, N, E::Signature, E::Id>>,
+	catch_up_requests: Fuse<E::CatchUpIn>,
+	catch_up_responses: Fuse<E::CatchUpOut>,
+}
+
+impl<H, N, E> BackgroundRound<H, N, E>
+where
+	H: Clone + Debug + Eq + Ord,
+	N: BlockNumberOps + Debug,
+	E: Environment<H, N>,
+{
+	fn handle_vote(&mut self, vote: SignedMessage<H, N, E::Signature, E::Id>) -> Result<(), E::Error> {
+		let SignedMessage {
+			message,
+			signature,
+			id,
+		} = vote;
+
+		if !self
+			.env
+			.is_equal_or_descendent_of(self.round.base().0, message.target().0.clone())
+		{
+			trace!(target: "afg",
+				"Ignoring message targeting {:?} lower than round base {:?} in background round {}",
+				message.target(), self.round.base(), self.round.number(),
+			);
+
+			return Ok(());
+		}
+
+		match message {
+			Message::Prevote(prevote) => {
+				let import_result = self
+					.round
+					.import_prevote(&*self.env, prevote, id, signature)?;
+
+				if let Some(equivocation) = import_result.equivocation {
+					self.env.prevote_equivocation(
+						self.round.number(),
+						EquivocationProof::from_prevote(equivocation),
+					);
+				}
+			}
+			Message::Precommit(precommit) => {
+				let import_result = self
+					.round
+					.import_precommit(&*self.env, precommit, id, signature)?;
+
+				if let Some(equivocation) = import_result.equivocation {
+					self.env.precommit_equivocation(
+						self.round.number(),
+						EquivocationProof::from_precommit(equivocation),
+					);
+				}
+			}
+			Message::PrimaryPropose(_) => {}
+		}
+
+		Ok(())
+	}
+
+	/// Assemble a catch-up proof for `target_round`. Returns `None` if
+	/// `target_round` isn't this round, or if we don't have enough imported
+	/// votes to justify the round's estimate.
+	fn construct_catch_up(&self, target_round: u64) -> Option<CatchUp<H, N, E::Signature, E::Id>> {
+		if target_round != self.round.number() || !self.round.completable() {
+			return None;
+		}
+
+		let historical_votes = self.round.historical_votes();
+
+		let prevotes = historical_votes.prevotes().to_vec();
+		let precommits = historical_votes.precommits().to_vec();
+
+		let (base_hash, base_number) = self.round.base();
+
+		Some(CatchUp {
+			round_number: self.round.number(),
+			prevotes,
+			precommits,
+			base_hash,
+			base_number,
+		})
+	}
+
+	/// Drive this background round until all of its input streams are
+	/// exhausted, importing late votes, responding to catch-up requests, and
+	/// emitting a commit via the environment every time the finalized block
+	/// advances.
+	pub async fn run(mut self) -> Result<(), E::Error> {
+		loop {
+			futures::select! {
+				vote = self.incoming.select_next_some() => {
+					self.handle_vote(vote?)?;
+				}
+				target_round = self.catch_up_requests.select_next_some() => {
+					if let Some(catch_up) = self.construct_catch_up(target_round) {
+						self.env.send_catch_up_response(target_round, catch_up)?;
+					}
+
+					continue;
+				}
+				catch_up = self.catch_up_responses.select_next_some() => {
+					// see the matching arm in `VotingRound::run`: we have no way
+					// to construct a fresh `Round` for an arbitrary round number
+					// in this background round either, so a proof for a round
+					// ahead of ours can't be acted on yet.
+					trace!(target: "afg",
+						"Background round {} received catch-up proof for round {}, \
+						ignoring",
+						self.round.number(), catch_up.round_number,
+					);
+
+					continue;
+				}
+				complete => return Ok(()),
+			}
+
+			if let Some((hash, number)) = self.round.finalized().cloned() {
+				let is_new_best = self
+					.finalized
+					.as_ref()
+					.map_or(true, |commit| number > commit.target_number);
+
+				if is_new_best {
+					debug!(target: "afg",
+						"Background round {} finalized block {:?} ({})",
+						self.round.number(), hash, number,
+					);
+
+					let precommits = self
+						.round
+						.historical_votes()
+						.precommits()
+						.iter()
+						.filter(|signed| {
+							self.env
+								.is_equal_or_descendent_of(hash.clone(), signed.precommit.target_hash.clone())
+						})
+						.cloned()
+						.collect();
+
+					let commit = Commit {
+						target_hash: hash,
+						target_number: number,
+						precommits,
+					};
+
+					self.env
+						.finalize_block(self.round.number(), commit.clone())?;
+					self.finalized = Some(commit);
+				}
+			}
+		}
+	}
+}
+
+/// A proof that a round reached a given estimate, carrying the prevotes and
+/// precommits that justify it. Sent in response to a catch-up request from
+/// a lagging peer (see `Environment::catch_up_requests` and
+/// `Environment::send_catch_up_response`) so that it can
+/// jump straight to the round that produced this proof instead of replaying
+/// every round since its own last seen one.
+#[derive(Debug, Clone)]
+pub struct CatchUp<H, N, Signature, Id> {
+	/// The round this proof is for.
+	pub round_number: u64,
+	/// The prevotes justifying the round's prevote-GHOST.
+	pub prevotes: Vec<SignedMessage<H, N, Signature, Id>>,
+	/// The precommits justifying the round's estimate.
+	pub precommits: Vec<SignedPrecommit<H, N, Signature, Id>>,
+	/// The round's base, i.e. the last finalized block it started from.
+	pub base_hash: H,
+	/// The block number of the round's base.
+	pub base_number: N,
+}
+
+/// Why an incoming catch-up proof was rejected.
+#[derive(Debug)]
+pub enum CatchUpError<E> {
+	/// The proof carried no prevotes at all.
+	MissingPrevotes,
+	/// Importing one of the proof's votes failed.
+	Import(E),
+	/// After importing every vote in the proof, the round still isn't
+	/// completable, so the estimate it claims isn't actually justified.
+	NotCompletable,
+}
+
+/// Validate an incoming catch-up proof by replaying its prevotes and
+/// precommits into `round`, a freshly started `Round` supplied by the caller.
+/// On success the round is completable with the proof's votes imported, and
+/// the caller can hand it straight to a new `VotingRound`, skipping every
+/// round in between instead of waiting for them to replay. The proof's
+/// `round_number`, `base_hash` and `base_number` are not compared against
+/// `round`, and entries in `prevotes` that are not prevotes are skipped.
+pub(super) fn validate_catch_up<H, N, E>(
+	env: &E,
+	mut round: Round<E::Id, H, N, E::Signature>,
+	catch_up: CatchUp<H, N, E::Signature, E::Id>,
+) -> Result<Round<E::Id, H, N, E::Signature>, CatchUpError<E::Error>>
+where
+	H: Clone + Debug + Eq + Ord,
+	N: BlockNumberOps + Debug,
+	E: Environment<H, N>,
+{
+	if catch_up.prevotes.is_empty() {
+		return Err(CatchUpError::MissingPrevotes);
+	}
+
+	for signed in catch_up.prevotes {
+		let SignedMessage {
+			message,
+			signature,
+			id,
+		} = signed;
+
+		if let Message::Prevote(prevote) = message {
+			round
+				.import_prevote(env, prevote, id, signature)
+				.map_err(CatchUpError::Import)?;
+		}
+	}
+
+	for signed in catch_up.precommits {
+		round
+			.import_precommit(env, signed.precommit, signed.id, signed.signature)
+			.map_err(CatchUpError::Import)?;
+	}
+
+	if round.completable() {
+		Ok(round)
+	} else {
+		Err(CatchUpError::NotCompletable)
+	}
+}
+
+/// A self-contained, independently verifiable proof that `identity` cast two
+/// conflicting votes in the same round. Built by `handle_vote` from the
+/// `Equivocation` that `Round::import_prevote`/`import_precommit` report, so
+/// that callers can persist and forward the proof (e.g. for slashing)
+/// without re-deriving it from raw round state.
+#[derive(Debug, Clone)]
+pub enum EquivocationProof<H, N, Signature, Id> {
+	/// Two conflicting prevotes cast by `identity` in the same round.
+	Prevote {
+		round_number: u64,
+		identity: Id,
+		first: SignedMessage<H, N, Signature, Id>,
+		second: SignedMessage<H, N, Signature, Id>,
+	},
+	/// Two conflicting precommits cast by `identity` in the same round.
+	Precommit {
+		round_number: u64,
+		identity: Id,
+		first: SignedPrecommit<H, N, Signature, Id>,
+		second: SignedPrecommit<H, N, Signature, Id>,
+	},
+}
+
+impl<H, N, Signature, Id> EquivocationProof<H, N, Signature, Id> {
+	/// Build a proof from a detected prevote equivocation.
+	pub fn from_prevote(equivocation: Equivocation<Id, Prevote<H, N>, Signature>) -> Self
+	where
+		Id: Clone,
+	{
+		let Equivocation {
+			round_number,
+			identity,
+			first,
+			second,
+		} = equivocation;
+
+		EquivocationProof::Prevote {
+			round_number,
+			first: SignedMessage {
+				message: Message::Prevote(first.0),
+				signature: first.1,
+				id: identity.clone(),
+			},
+			second: SignedMessage {
+				message: Message::Prevote(second.0),
+				signature: second.1,
+				id: identity.clone(),
+			},
+			identity,
+		}
+	}
+
+	/// Build a proof from a detected precommit equivocation.
+	pub fn from_precommit(equivocation: Equivocation<Id, Precommit<H, N>, Signature>) -> Self
+	where
+		Id: Clone,
+	{
+		let Equivocation {
+			round_number,
+			identity,
+			first,
+			second,
+		} = equivocation;
+
+		EquivocationProof::Precommit {
+			round_number,
+			first: SignedPrecommit {
+				precommit: first.0,
+				signature: first.1,
+				id: identity.clone(),
+			},
+			second: SignedPrecommit {
+				precommit: second.0,
+				signature: second.1,
+				id: identity.clone(),
+			},
+			identity,
+		}
+	}
+}
+
+/// Re-verify an `EquivocationProof`: check that both of its signatures are
+/// valid for `identity` and that the two votes actually target different
+/// blocks (otherwise it's just the same vote retransmitted, not an
+/// equivocation). This crate is agnostic to the signature scheme in use, so
+/// the actual cryptographic check is supplied by the caller.
+pub fn verify_equivocation_proof<H, N, Signature, Id>(
+	proof: &EquivocationProof<H, N, Signature, Id>,
+	check_signature: impl Fn(&Id, &Message<H, N>, &Signature) -> bool,
+) -> bool
+where
+	H: Clone + Eq,
+	N: Clone + Eq,
+{
+	match proof {
+		EquivocationProof::Prevote {
+			identity,
+			first,
+			second,
+			..
+		} => {
+			first.message.target() != second.message.target()
+				&& check_signature(identity, &first.message, &first.signature)
+				&& check_signature(identity, &second.message, &second.signature)
+		}
+		EquivocationProof::Precommit {
+			identity,
+			first,
+			second,
+			..
+		} => {
+			let first_message = Message::Precommit(first.precommit.clone());
+			let second_message = Message::Precommit(second.precommit.clone());
+
+			(first.precommit.target_hash.clone(), first.precommit.target_number.clone())
+				!= (second.precommit.target_hash.clone(), second.precommit.target_number.clone())
+				&& check_signature(identity, &first_message, &first.signature)
+				&& check_signature(identity, &second_message, &second.signature)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn prevote_proof(
+		first_target: u32,
+		second_target: u32,
+		first_signature: u8,
+		second_signature: u8,
+	) -> EquivocationProof<u8, u32, u8, u8> {
+		EquivocationProof::Prevote {
+			round_number: 1,
+			identity: 1,
+			first: SignedMessage {
+				message: Message::Prevote(Prevote {
+					target_hash: first_target as u8,
+					target_number: first_target,
+				}),
+				signature: first_signature,
+				id: 1,
+			},
+			second: SignedMessage {
+				message: Message::Prevote(Prevote {
+					target_hash: second_target as u8,
+					target_number: second_target,
+				}),
+				signature: second_signature,
+				id: 1,
+			},
+		}
+	}
+
+	// a trivial "signature" scheme for tests: a message is validly signed by
+	// `id` iff `signature == id`.
+	fn check_signature(id: &u8, _message: &Message<u8, u32>, signature: &u8) -> bool {
+		signature == id
+	}
+
+	#[test]
+	fn verify_equivocation_proof_accepts_valid_proof() {
+		let proof = prevote_proof(1, 2, 1, 1);
+		assert!(verify_equivocation_proof(&proof, check_signature));
+	}
+
+	#[test]
+	fn verify_equivocation_proof_rejects_equal_targets() {
+		let proof = prevote_proof(1, 1, 1, 1);
+		assert!(!verify_equivocation_proof(&proof, check_signature));
+	}
+
+	#[test]
+	fn verify_equivocation_proof_rejects_bad_signature() {
+		let proof = prevote_proof(1, 2, 1, 2);
+		assert!(!verify_equivocation_proof(&proof, check_signature));
+	}
+
+	#[test]
+	fn verify_equivocation_proof_precommit_rejects_equal_targets() {
+		let proof = EquivocationProof::Precommit {
+			round_number: 1,
+			identity: 1u8,
+			first: SignedPrecommit {
+				precommit: Precommit {
+					target_hash: 1u8,
+					target_number: 1u32,
+				},
+				signature: 1u8,
+				id: 1u8,
+			},
+			second: SignedPrecommit {
+				precommit: Precommit {
+					target_hash: 1,
+					target_number: 1,
+				},
+				signature: 1,
+				id: 1,
+			},
+		};
+
+		assert!(!verify_equivocation_proof(&proof, check_signature));
+	}
 }